@@ -1,8 +1,12 @@
-use alloy_primitives::{Address, Bytes};
+use alloy_chains::{Chain, NamedChain};
+use alloy_dyn_abi::{DynSolType, DynSolValue};
+use alloy_json_abi::{Constructor, JsonAbi};
+use alloy_primitives::{keccak256, Address, Bytes, B256};
 use alloy_provider::{ext::TraceApi, Provider};
 use alloy_rpc_types::trace::parity::{Action, CreateAction, CreateOutput, TraceOutput};
+use async_trait::async_trait;
 use cast::SimpleCast;
-use clap::{command, Parser};
+use clap::{command, Parser, ValueEnum};
 use eyre::Result;
 use foundry_block_explorers::Client;
 use foundry_cli::{
@@ -11,8 +15,7 @@ use foundry_cli::{
 };
 use foundry_common::provider::RetryProvider;
 use foundry_config::Config;
-
-use super::interface::fetch_abi_from_etherscan;
+use serde::Serialize;
 
 /// CLI arguments for `cast creation-code`.
 #[derive(Parser)]
@@ -32,6 +35,47 @@ pub struct CreationCodeArgs {
     #[arg(long)]
     only_args: bool,
 
+    /// Print the decoded constructor argument values instead of raw hex.
+    #[arg(long)]
+    decode_args: bool,
+
+    /// Locate the creation code using only the RPC provider, without relying on an
+    /// Etherscan-compatible explorer. Useful for chains without an indexer, or deployments too
+    /// recent for the indexer to have picked up.
+    #[arg(long)]
+    rpc_only: bool,
+
+    /// Lower bound block to start searching from when `--rpc-only` is set. Defaults to 0 when the
+    /// contract has code at the latest block; required when it has none.
+    #[arg(long, requires = "rpc_only")]
+    from_block: Option<u64>,
+
+    /// When `--rpc-only` finds more than one `CREATE`/`CREATE2` trace for `contract` in its
+    /// creation block (e.g. a CREATE2 redeployment at the same address), select the one whose
+    /// init code hashes to this value instead of erroring out.
+    #[arg(long, requires = "rpc_only")]
+    init_code_hash: Option<B256>,
+
+    /// Print deployment provenance (method, deployer, creation tx hash and init-code hash) as
+    /// JSON instead of the raw creation bytecode.
+    #[arg(long)]
+    json: bool,
+
+    /// Recompute the expected CREATE2 address from the deployer, `--salt` and the init-code
+    /// hash, and assert that it matches `contract`. Requires `--salt`.
+    #[arg(long, requires = "salt")]
+    verify_create2: bool,
+
+    /// The 32-byte salt used for the CREATE2 deployment being verified via `--verify-create2`.
+    #[arg(long)]
+    salt: Option<B256>,
+
+    /// Metadata backend(s) to query for the creation transaction and ABI, in priority order.
+    /// May be passed multiple times; the first backend that succeeds wins. Defaults to
+    /// `etherscan`, falling back to `sourcify` and `blockscout`.
+    #[arg(long = "explorer", value_enum)]
+    explorers: Vec<ExplorerBackend>,
+
     #[command(flatten)]
     etherscan: EtherscanOpts,
     #[command(flatten)]
@@ -40,24 +84,73 @@ pub struct CreationCodeArgs {
 
 impl CreationCodeArgs {
     pub async fn run(self) -> Result<()> {
-        let Self { contract, etherscan, rpc, disassemble, without_args, only_args } = self;
+        let Self {
+            contract,
+            etherscan,
+            rpc,
+            disassemble,
+            without_args,
+            only_args,
+            decode_args,
+            rpc_only,
+            from_block,
+            init_code_hash,
+            json,
+            verify_create2,
+            salt,
+            explorers,
+        } = self;
 
         if without_args && only_args {
             return Err(eyre::eyre!("--without-args and --only-args are mutually exclusive."));
         }
-
-        let config = Config::from(&etherscan);
-        let chain = config.chain.unwrap_or_default();
-        let api_key = config.get_etherscan_api_key(Some(chain)).unwrap_or_default();
-        let client = Client::new(chain, api_key)?;
+        if decode_args && (without_args || only_args) {
+            return Err(eyre::eyre!(
+                "--decode-args cannot be combined with --without-args or --only-args."
+            ));
+        }
+        if rpc_only && (without_args || only_args || decode_args) {
+            return Err(eyre::eyre!(
+                "--rpc-only cannot be combined with --without-args, --only-args or --decode-args \
+                 since those require an ABI from an explorer."
+            ));
+        }
 
         let config = Config::from(&rpc);
         let provider = utils::get_provider(&config)?;
 
-        let bytecode = fetch_creation_code(contract, client, provider).await?;
+        let (bytecode, provenance) = if rpc_only {
+            fetch_creation_code_rpc(contract, &provider, from_block, init_code_hash).await?
+        } else {
+            let metadata_providers = build_metadata_providers(&explorers, &etherscan)?;
+            fetch_creation_code(contract, &metadata_providers, provider).await?
+        };
+
+        if verify_create2 {
+            let salt = salt.expect("clap enforces --salt is present alongside --verify-create2");
+            verify_create2_address(contract, provenance.deployer, salt, &bytecode)?;
+        }
+
+        if json {
+            let output = CreationCodeOutput { contract, provenance };
+            println!("{}", serde_json::to_string_pretty(&output)?);
+            return Ok(());
+        }
+
+        if decode_args {
+            let metadata_providers = build_metadata_providers(&explorers, &etherscan)?;
+            return print_decoded_constructor_args(bytecode, contract, &metadata_providers).await;
+        }
 
+        // `--rpc-only` excludes the flags that need an ABI, so no explorer is consulted here.
+        let metadata_providers = if rpc_only {
+            Vec::new()
+        } else {
+            build_metadata_providers(&explorers, &etherscan)?
+        };
         let bytecode =
-            parse_code_output(bytecode, contract, &etherscan, without_args, only_args).await?;
+            parse_code_output(bytecode, contract, &metadata_providers, without_args, only_args)
+                .await?;
 
         if disassemble {
             println!("{}", SimpleCast::disassemble(&bytecode)?);
@@ -69,12 +162,361 @@ impl CreationCodeArgs {
     }
 }
 
+/// How a contract's creation code was deployed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CreationMethod {
+    Create,
+    Create2,
+}
+
+/// Deployment provenance for a contract's creation code.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreationProvenance {
+    /// Whether the contract was deployed via a plain `CREATE` or via `CREATE2`.
+    ///
+    /// Parity-style traces don't label which opcode produced a `Create` action, so this is
+    /// inferred rather than read directly: a top-level transaction (`to == None`) can only use
+    /// `CREATE`, while anything created through an internal trace is reported as `CREATE2`,
+    /// since deterministic factory deployments are virtually always `CREATE2`.
+    pub method: CreationMethod,
+    /// The address that submitted the creation transaction, or the factory that performed the
+    /// internal `CREATE`/`CREATE2`.
+    pub deployer: Address,
+    /// The hash of the transaction that deployed the contract.
+    pub creation_tx_hash: B256,
+    /// `keccak256` of the init code.
+    pub init_code_hash: B256,
+}
+
+#[derive(Serialize)]
+struct CreationCodeOutput {
+    contract: Address,
+    #[serde(flatten)]
+    provenance: CreationProvenance,
+}
+
+/// A metadata backend that `cast creation-code` can query for a contract's creation transaction
+/// and verified ABI.
+///
+/// `CreationCodeArgs` stacks these (Etherscan, Sourcify, Blockscout, ...) and tries each in
+/// order, taking the first success, so a single indexer being down or missing a contract doesn't
+/// make the command unusable.
+#[async_trait]
+pub(crate) trait CodeMetadataProvider: Send + Sync {
+    /// Name used to identify this backend in error messages.
+    fn name(&self) -> &'static str;
+
+    /// Returns the creation transaction hash and deployer for `contract`.
+    async fn contract_creation_data(&self, contract: Address) -> Result<ContractCreationInfo>;
+
+    /// Returns the verified ABI for `contract`.
+    async fn source_abi(&self, contract: Address) -> Result<JsonAbi>;
+}
+
+/// The subset of a contract-creation lookup that every [`CodeMetadataProvider`] backend needs to
+/// report, normalized across backends with differing response shapes.
+pub(crate) struct ContractCreationInfo {
+    transaction_hash: B256,
+    deployer: Address,
+}
+
+/// Which [`CodeMetadataProvider`] backend to query.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "lowercase")]
+enum ExplorerBackend {
+    Etherscan,
+    Sourcify,
+    Blockscout,
+}
+
+/// Builds the ordered stack of metadata backends to try, defaulting to
+/// `[Etherscan, Sourcify, Blockscout]` when `--explorer` wasn't passed.
+pub(crate) fn build_metadata_providers(
+    explorers: &[ExplorerBackend],
+    etherscan: &EtherscanOpts,
+) -> Result<Vec<Box<dyn CodeMetadataProvider>>> {
+    const DEFAULT_ORDER: [ExplorerBackend; 3] =
+        [ExplorerBackend::Etherscan, ExplorerBackend::Sourcify, ExplorerBackend::Blockscout];
+    let explicit = !explorers.is_empty();
+    let explorers = if explicit { explorers } else { &DEFAULT_ORDER[..] };
+
+    let config = Config::from(etherscan);
+    let chain = config.chain.unwrap_or_default();
+
+    explorers
+        .iter()
+        .map(|backend| -> Result<Box<dyn CodeMetadataProvider>> {
+            Ok(match backend {
+                ExplorerBackend::Etherscan => {
+                    let api_key = config.get_etherscan_api_key(Some(chain)).unwrap_or_default();
+                    Box::new(EtherscanCompatibleProvider {
+                        name: "etherscan",
+                        client: Client::new(chain, api_key)?,
+                    })
+                }
+                ExplorerBackend::Blockscout => {
+                    let api_key = config.get_etherscan_api_key(Some(chain)).unwrap_or_default();
+                    let (api_url, browser_url) = blockscout_urls(chain)?;
+                    Box::new(EtherscanCompatibleProvider {
+                        name: "blockscout",
+                        client: Client::builder()
+                            .chain(chain)?
+                            .with_api_url(api_url)?
+                            .with_url(browser_url)?
+                            .with_api_key(api_key)
+                            .build()?,
+                    })
+                }
+                ExplorerBackend::Sourcify => {
+                    Box::new(SourcifyMetadataProvider { chain_id: chain.id() })
+                }
+            })
+        })
+        // The default stack skips backends that can't be built for this chain; a backend passed
+        // explicitly via `--explorer` still reports why it can't be used.
+        .filter(|provider| explicit || provider.is_ok())
+        .collect()
+}
+
+/// Returns Blockscout's own API and browser base URLs for `chain`, distinct from the Etherscan
+/// URL that `ClientBuilder::chain` would otherwise fill in - reusing that one would just hit
+/// Etherscan again under a different name, defeating the point of stacking a second backend.
+fn blockscout_urls(chain: Chain) -> Result<(String, String)> {
+    let subdomain = match chain.named() {
+        Some(NamedChain::Mainnet) => "eth",
+        Some(NamedChain::Sepolia) => "eth-sepolia",
+        Some(NamedChain::Optimism) => "optimism",
+        Some(NamedChain::Base) => "base",
+        Some(NamedChain::Arbitrum) => "arbitrum",
+        Some(NamedChain::Gnosis) => "gnosis",
+        Some(NamedChain::Polygon) => "polygon",
+        _ => {
+            return Err(eyre::eyre!(
+                "No known Blockscout instance for chain {chain}; pass --explorer etherscan or \
+                 --explorer sourcify instead, or add this chain to `blockscout_urls`."
+            ))
+        }
+    };
+
+    let api_url = format!("https://{subdomain}.blockscout.com/api");
+    let browser_url = format!("https://{subdomain}.blockscout.com");
+    Ok((api_url, browser_url))
+}
+
+#[cfg(test)]
+mod blockscout_urls_tests {
+    use super::*;
+
+    #[test]
+    fn blockscout_urls_known_chain() {
+        let (api_url, browser_url) =
+            blockscout_urls(Chain::from_named(NamedChain::Mainnet)).unwrap();
+        assert_eq!(api_url, "https://eth.blockscout.com/api");
+        assert_eq!(browser_url, "https://eth.blockscout.com");
+    }
+
+    #[test]
+    fn blockscout_urls_unknown_chain_errors() {
+        assert!(blockscout_urls(Chain::from_id(999_999_999)).is_err());
+    }
+}
+
+/// Tries every backend in order, returning the first one that resolves successfully. If all of
+/// them fail, reports every backend's error so the user can tell which indexers were tried.
+async fn resolve_creation_data(
+    providers: &[Box<dyn CodeMetadataProvider>],
+    contract: Address,
+) -> Result<ContractCreationInfo> {
+    let mut errors = Vec::new();
+    for provider in providers {
+        match provider.contract_creation_data(contract).await {
+            Ok(data) => return Ok(data),
+            Err(e) => errors.push(format!("{}: {e}", provider.name())),
+        }
+    }
+    Err(eyre::eyre!(
+        "Could not resolve creation data for {contract} from any metadata backend:\n{}",
+        errors.join("\n")
+    ))
+}
+
+/// Tries every backend in order, returning the first ABI that resolves successfully.
+async fn resolve_source_abi(
+    providers: &[Box<dyn CodeMetadataProvider>],
+    contract: Address,
+) -> Result<JsonAbi> {
+    let mut errors = Vec::new();
+    for provider in providers {
+        match provider.source_abi(contract).await {
+            Ok(abi) => return Ok(abi),
+            Err(e) => errors.push(format!("{}: {e}", provider.name())),
+        }
+    }
+    Err(eyre::eyre!(
+        "Could not resolve an ABI for {contract} from any metadata backend:\n{}",
+        errors.join("\n")
+    ))
+}
+
+/// A [`CodeMetadataProvider`] backed by an Etherscan-compatible REST API - either Etherscan
+/// itself, or any indexer (e.g. Blockscout) that mirrors Etherscan's `contract` module endpoints.
+struct EtherscanCompatibleProvider {
+    name: &'static str,
+    client: Client,
+}
+
+#[async_trait]
+impl CodeMetadataProvider for EtherscanCompatibleProvider {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    async fn contract_creation_data(&self, contract: Address) -> Result<ContractCreationInfo> {
+        let data = self.client.contract_creation_data(contract).await?;
+        Ok(ContractCreationInfo {
+            transaction_hash: data.transaction_hash,
+            deployer: data.contract_creator,
+        })
+    }
+
+    async fn source_abi(&self, contract: Address) -> Result<JsonAbi> {
+        let metadata = self.client.contract_source_code(contract).await?;
+        let item = metadata
+            .items
+            .into_iter()
+            .next()
+            .ok_or_else(|| eyre::eyre!("No ABI found via {}.", self.name))?;
+        item.abi().map_err(|e| eyre::eyre!("Could not parse ABI returned by {}: {e}", self.name))
+    }
+}
+
+/// A [`CodeMetadataProvider`] backed by [Sourcify](https://sourcify.dev), a trustless,
+/// verification-based source-code registry.
+///
+/// Sourcify doesn't index creation transactions, only verified sources, so
+/// `contract_creation_data` always fails here - that's fine, since [`resolve_creation_data`] just
+/// falls through to the next backend in the stack.
+struct SourcifyMetadataProvider {
+    chain_id: u64,
+}
+
+#[async_trait]
+impl CodeMetadataProvider for SourcifyMetadataProvider {
+    fn name(&self) -> &'static str {
+        "sourcify"
+    }
+
+    async fn contract_creation_data(&self, _contract: Address) -> Result<ContractCreationInfo> {
+        Err(eyre::eyre!("Sourcify does not index contract creation transactions."))
+    }
+
+    async fn source_abi(&self, contract: Address) -> Result<JsonAbi> {
+        let url = format!(
+            "https://sourcify.dev/server/files/any/{}/{contract}",
+            self.chain_id
+        );
+        let files: SourcifyFilesResponse = reqwest::get(url)
+            .await?
+            .error_for_status()
+            .map_err(|e| eyre::eyre!("Sourcify has no verified source for {contract}: {e}"))?
+            .json()
+            .await?;
+
+        let metadata_file = files
+            .files
+            .iter()
+            .find(|file| file.name == "metadata.json")
+            .ok_or_else(|| eyre::eyre!("No metadata.json found on Sourcify for {contract}."))?;
+        let metadata: SourcifyMetadata = serde_json::from_str(&metadata_file.content)?;
+
+        Ok(metadata.output.abi)
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct SourcifyFilesResponse {
+    files: Vec<SourcifyFile>,
+}
+
+#[derive(serde::Deserialize)]
+struct SourcifyFile {
+    name: String,
+    content: String,
+}
+
+#[derive(serde::Deserialize)]
+struct SourcifyMetadata {
+    output: SourcifyMetadataOutput,
+}
+
+#[derive(serde::Deserialize)]
+struct SourcifyMetadataOutput {
+    abi: JsonAbi,
+}
+
+/// Recomputes the expected CREATE2 address as
+/// `keccak256(0xff ++ deployer ++ salt ++ keccak256(init_code))[12:]` and errors if it doesn't
+/// match `contract`.
+fn verify_create2_address(
+    contract: Address,
+    deployer: Address,
+    salt: B256,
+    init_code: &Bytes,
+) -> Result<()> {
+    let init_code_hash = keccak256(init_code);
+
+    let mut preimage = Vec::with_capacity(1 + 20 + 32 + 32);
+    preimage.push(0xff);
+    preimage.extend_from_slice(deployer.as_slice());
+    preimage.extend_from_slice(salt.as_slice());
+    preimage.extend_from_slice(init_code_hash.as_slice());
+
+    let expected = Address::from_word(keccak256(preimage));
+    if expected != contract {
+        return Err(eyre::eyre!(
+            "Computed CREATE2 address {expected} does not match {contract}; this deployment was \
+             not produced by deployer {deployer} with salt {salt} and this init code."
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod create2_tests {
+    use super::*;
+
+    // Example 0 from EIP-1014 (https://eips.ethereum.org/EIPS/eip-1014#examples): an
+    // independently-known CREATE2 vector, so this exercises the actual preimage formula instead
+    // of re-deriving the expected address with the same code under test.
+    #[test]
+    fn verify_create2_address_matches_expected() {
+        let deployer: Address = "0x0000000000000000000000000000000000000000".parse().unwrap();
+        let salt = B256::ZERO;
+        let init_code = Bytes::from(vec![0x00]);
+        let expected: Address = "0x4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38".parse().unwrap();
+
+        assert!(verify_create2_address(expected, deployer, salt, &init_code).is_ok());
+    }
+
+    #[test]
+    fn verify_create2_address_rejects_mismatch() {
+        let deployer = Address::repeat_byte(0x11);
+        let salt = B256::repeat_byte(0x22);
+        let init_code = Bytes::from(vec![0x60, 0x01, 0x60, 0x01, 0x55]);
+        let wrong_contract = Address::repeat_byte(0x99);
+
+        assert!(verify_create2_address(wrong_contract, deployer, salt, &init_code).is_err());
+    }
+}
+
 /// Parses the creation bytecode to return either the bytecode, or bytecoe without constructor
 /// arguments or only the constructor arguments.
 async fn parse_code_output(
     bytecode: Bytes,
     contract: Address,
-    etherscan: &EtherscanOpts,
+    metadata_providers: &[Box<dyn CodeMetadataProvider>],
     without_args: bool,
     only_args: bool,
 ) -> Result<Bytes> {
@@ -82,9 +524,7 @@ async fn parse_code_output(
         return Ok(bytecode);
     }
 
-    let abi = fetch_abi_from_etherscan(contract, etherscan).await?;
-    let abi = abi.into_iter().next().ok_or_else(|| eyre::eyre!("No ABI found."))?;
-    let (abi, _) = abi;
+    let abi = resolve_source_abi(metadata_providers, contract).await?;
 
     if abi.constructor.is_none() {
         if only_args {
@@ -101,37 +541,231 @@ async fn parse_code_output(
         return Ok(bytecode);
     }
 
-    let args_size = constructor.inputs.len() * 32;
+    let (code, args) = split_constructor_args(&bytecode, &constructor)?;
 
-    let bytecode = if without_args {
-        Bytes::from(bytecode[..bytecode.len() - args_size].to_vec())
-    } else if only_args {
-        Bytes::from(bytecode[bytecode.len() - args_size..].to_vec())
-    } else {
-        panic!("Unreachable.")
+    Ok(if without_args { code } else { args })
+}
+
+/// Decodes and prints the constructor arguments contained in `bytecode`, using the ABI resolved
+/// from the first metadata backend that has it, instead of returning the raw encoded bytes.
+async fn print_decoded_constructor_args(
+    bytecode: Bytes,
+    contract: Address,
+    metadata_providers: &[Box<dyn CodeMetadataProvider>],
+) -> Result<()> {
+    let abi = resolve_source_abi(metadata_providers, contract).await?;
+
+    let constructor = abi.constructor.ok_or_else(|| eyre::eyre!("No constructor found."))?;
+    if constructor.inputs.is_empty() {
+        return Err(eyre::eyre!("No constructor arguments found."));
+    }
+
+    let (_, args) = split_constructor_args(&bytecode, &constructor)?;
+
+    let types = constructor
+        .inputs
+        .iter()
+        .map(|input| input.resolve())
+        .collect::<alloy_dyn_abi::Result<Vec<_>>>()
+        .map_err(|e| eyre::eyre!("Could not resolve constructor input types: {e}"))?;
+
+    let DynSolValue::Tuple(values) = DynSolType::Tuple(types).abi_decode_params(&args)? else {
+        eyre::bail!("Expected constructor arguments to decode into a tuple.");
     };
 
-    Ok(bytecode)
+    for (input, value) in constructor.inputs.iter().zip(values) {
+        println!("{}: {value:?}", input.name);
+    }
+
+    Ok(())
 }
 
-/// Fetches the creation code of a contract from Etherscan and RPC.
-pub async fn fetch_creation_code(
+/// Splits creation bytecode into `(code_without_args, constructor_args)`.
+///
+/// ABI encodings are 32-byte aligned and self-describing, so we can't just assume
+/// `constructor.inputs.len() * 32` bytes were appended: any dynamic input (`string`, `bytes`,
+/// arrays, nested tuples containing those) is encoded as a 32-byte offset in the head plus a
+/// variable-length tail. Instead, walk candidate trailing regions of size `32 * k` for
+/// `k = 1, 2, ...` and accept the smallest one that fully decodes as
+/// `DynSolType::Tuple(constructor_input_types)` with no leftover bytes and all offsets in range.
+/// When every input is statically sized we can skip the search entirely and take the exact
+/// known size.
+fn split_constructor_args(bytecode: &Bytes, constructor: &Constructor) -> Result<(Bytes, Bytes)> {
+    let types = constructor
+        .inputs
+        .iter()
+        .map(|input| input.resolve())
+        .collect::<alloy_dyn_abi::Result<Vec<_>>>()
+        .map_err(|e| eyre::eyre!("Could not resolve constructor input types: {e}"))?;
+
+    if let Some(size) = static_args_size(&types) {
+        if size > bytecode.len() {
+            return Err(eyre::eyre!(
+                "Creation bytecode is shorter than the expected constructor arguments."
+            ));
+        }
+        let split_at = bytecode.len() - size;
+        return Ok((
+            Bytes::from(bytecode[..split_at].to_vec()),
+            Bytes::from(bytecode[split_at..].to_vec()),
+        ));
+    }
+
+    let tuple = DynSolType::Tuple(types);
+    let max_words = bytecode.len() / 32;
+    for k in 1..=max_words {
+        let split_at = bytecode.len() - 32 * k;
+        let candidate = &bytecode[split_at..];
+        // A decode can succeed while leaving part of the candidate unread, so also require that
+        // re-encoding the decoded values reproduces the whole candidate.
+        let fully_decoded = tuple
+            .abi_decode_params(candidate)
+            .is_ok_and(|value| value.abi_encode_params().as_slice() == candidate);
+        if fully_decoded {
+            return Ok((
+                Bytes::from(bytecode[..split_at].to_vec()),
+                Bytes::from(candidate.to_vec()),
+            ));
+        }
+    }
+
+    Err(eyre::eyre!(
+        "Could not locate the constructor argument boundary in the creation bytecode."
+    ))
+}
+
+/// Returns the total encoded size of `types` if every one of them is statically sized, or `None`
+/// if any of them is dynamic (and a boundary search is required instead).
+fn static_args_size(types: &[DynSolType]) -> Option<usize> {
+    types.iter().map(static_type_size).sum()
+}
+
+fn static_type_size(ty: &DynSolType) -> Option<usize> {
+    match ty {
+        DynSolType::Bool
+        | DynSolType::Int(_)
+        | DynSolType::Uint(_)
+        | DynSolType::Address
+        | DynSolType::FixedBytes(_)
+        | DynSolType::Function => Some(32),
+        DynSolType::FixedArray(inner, len) => static_type_size(inner).map(|size| size * len),
+        DynSolType::Tuple(types) => types.iter().map(static_type_size).sum(),
+        DynSolType::CustomStruct { tuple, .. } => tuple.iter().map(static_type_size).sum(),
+        DynSolType::Bytes | DynSolType::String | DynSolType::Array(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod constructor_args_tests {
+    use super::*;
+    use alloy_primitives::U256;
+
+    /// Builds a `constructor(<types>)` ABI entry for testing, without needing a full contract
+    /// ABI JSON blob.
+    fn constructor_with_types(types: &[&str]) -> Constructor {
+        let inputs: Vec<_> = types
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| serde_json::json!({ "name": format!("arg{i}"), "type": ty }))
+            .collect();
+        serde_json::from_value(serde_json::json!({
+            "type": "constructor",
+            "stateMutability": "nonpayable",
+            "inputs": inputs,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn static_type_size_is_none_for_dynamic_types() {
+        assert_eq!(static_type_size(&DynSolType::String), None);
+        assert_eq!(static_type_size(&DynSolType::Bytes), None);
+        assert_eq!(static_type_size(&DynSolType::Array(Box::new(DynSolType::Uint(256)))), None);
+        assert_eq!(
+            static_type_size(&DynSolType::Tuple(vec![DynSolType::Uint(256), DynSolType::String])),
+            None
+        );
+    }
+
+    #[test]
+    fn static_type_size_sums_static_types() {
+        assert_eq!(static_type_size(&DynSolType::Bool), Some(32));
+        assert_eq!(static_type_size(&DynSolType::Address), Some(32));
+        assert_eq!(
+            static_type_size(&DynSolType::FixedArray(Box::new(DynSolType::Uint(256)), 3)),
+            Some(96)
+        );
+        assert_eq!(
+            static_type_size(&DynSolType::Tuple(vec![DynSolType::Uint(256), DynSolType::Bool])),
+            Some(64)
+        );
+    }
+
+    #[test]
+    fn split_constructor_args_static_fast_path() {
+        let constructor = constructor_with_types(&["uint256", "address"]);
+        let creation_code = vec![0x60, 0x01, 0x60, 0x01, 0x55];
+        let args = DynSolValue::Tuple(vec![
+            DynSolValue::Uint(U256::from(42), 256),
+            DynSolValue::Address(Address::repeat_byte(0x11)),
+        ])
+        .abi_encode_params();
+
+        let mut bytecode = creation_code.clone();
+        bytecode.extend_from_slice(&args);
+
+        let (without_args, only_args) =
+            split_constructor_args(&Bytes::from(bytecode), &constructor).unwrap();
+        assert_eq!(without_args, Bytes::from(creation_code));
+        assert_eq!(only_args, Bytes::from(args));
+    }
+
+    #[test]
+    fn split_constructor_args_dynamic_type_boundary_search() {
+        let constructor = constructor_with_types(&["string", "uint256"]);
+        let creation_code = vec![0x60, 0x01, 0x60, 0x01, 0x55];
+        let args = DynSolValue::Tuple(vec![
+            DynSolValue::String("hello constructor".into()),
+            DynSolValue::Uint(U256::from(7), 256),
+        ])
+        .abi_encode_params();
+
+        let mut bytecode = creation_code.clone();
+        bytecode.extend_from_slice(&args);
+
+        let (without_args, only_args) =
+            split_constructor_args(&Bytes::from(bytecode), &constructor).unwrap();
+        assert_eq!(without_args, Bytes::from(creation_code));
+        assert_eq!(only_args, Bytes::from(args));
+    }
+
+    #[test]
+    fn split_constructor_args_errors_when_bytecode_too_short() {
+        let constructor = constructor_with_types(&["uint256"]);
+        let bytecode = Bytes::from(vec![0u8; 16]);
+        assert!(split_constructor_args(&bytecode, &constructor).is_err());
+    }
+}
+
+/// Fetches the creation code of a contract from the metadata backends and RPC, along with its
+/// deployment provenance.
+pub(crate) async fn fetch_creation_code(
     contract: Address,
-    client: Client,
+    metadata_providers: &[Box<dyn CodeMetadataProvider>],
     provider: RetryProvider,
-) -> Result<Bytes> {
-    let creation_data = client.contract_creation_data(contract).await?;
+) -> Result<(Bytes, CreationProvenance)> {
+    let creation_data = resolve_creation_data(metadata_providers, contract).await?;
     let creation_tx_hash = creation_data.transaction_hash;
     let tx_data = provider.get_transaction_by_hash(creation_tx_hash).await?;
     let tx_data = tx_data.ok_or_else(|| eyre::eyre!("Could not find creation tx data."))?;
 
-    let bytecode = if tx_data.inner.to.is_none() {
+    let (bytecode, method, deployer) = if tx_data.inner.to.is_none() {
         // Contract was created using a standard transaction
-        tx_data.inner.input
+        (tx_data.inner.input, CreationMethod::Create, tx_data.inner.from)
     } else {
         // Contract was created using a factory pattern or create2
         // Extract creation code from tx traces
-        let mut creation_bytecode = None;
+        let mut creation = None;
 
         let traces = provider.trace_transaction(creation_tx_hash).await.map_err(|e| {
             eyre::eyre!("Could not fetch traces for transaction {}: {}", creation_tx_hash, e)
@@ -142,18 +776,315 @@ pub async fn fetch_creation_code(
                 trace.trace.result
             {
                 if address == contract {
-                    creation_bytecode = match trace.trace.action {
-                        Action::Create(CreateAction { init, value: _, from: _, gas: _ }) => {
-                            Some(init)
-                        }
-                        _ => None,
-                    };
+                    if let Action::Create(CreateAction { init, value: _, from, gas: _ }) =
+                        trace.trace.action
+                    {
+                        creation = Some((init, from));
+                    }
                 }
             }
         }
 
-        creation_bytecode.ok_or_else(|| eyre::eyre!("Could not find contract creation trace."))?
+        let (init, from) =
+            creation.ok_or_else(|| eyre::eyre!("Could not find contract creation trace."))?;
+        (init, CreationMethod::Create2, from)
     };
 
-    Ok(bytecode)
+    let init_code_hash = keccak256(&bytecode);
+    let provenance = CreationProvenance { method, deployer, creation_tx_hash, init_code_hash };
+
+    Ok((bytecode, provenance))
+}
+
+/// Locates the creation code of a contract using only the RPC provider, without relying on an
+/// Etherscan-compatible explorer.
+///
+/// This binary-searches block heights with `eth_getCode` to find the first block in which the
+/// account has code, then scans that block's traces (`trace_block`) for the `CREATE`/`CREATE2`
+/// action that produced it, reusing the same trace-walking logic as [`fetch_creation_code`].
+/// When the account has no code at the latest block, it instead scans forward block by block
+/// from `from_block`, which is then required.
+pub(crate) async fn fetch_creation_code_rpc(
+    contract: Address,
+    provider: &RetryProvider,
+    from_block: Option<u64>,
+    select_init_code_hash: Option<B256>,
+) -> Result<(Bytes, CreationProvenance)> {
+    let low = from_block.unwrap_or(0);
+    let high = provider.get_block_number().await?;
+
+    let creation_block = if !provider.get_code_at(contract).await?.is_empty() {
+        binary_search_creation_block(contract, provider, low, high).await?
+    } else {
+        // The account currently has no code: either it never had any (an EOA), or it was
+        // created and later self-destructed. Code presence is no longer monotonic in the
+        // latter case, so a binary search over `eth_getCode` can't be trusted - fall back to
+        // scanning forward block by block instead. That's only tractable over a bounded range,
+        // so require the caller to narrow it with `--from-block` rather than silently kicking
+        // off millions of sequential RPC calls from genesis.
+        let Some(from_block) = from_block else {
+            return Err(eyre::eyre!(
+                "{contract} has no code at the latest block; it may be an EOA that never had \
+                 code, or a contract that was self-destructed. Pass --from-block near the \
+                 suspected deployment to search for its creation block."
+            ));
+        };
+
+        if high.saturating_sub(from_block) > MAX_FORWARD_SCAN_BLOCKS {
+            return Err(eyre::eyre!(
+                "--from-block is {} blocks behind the latest block, which is more than the \
+                 {MAX_FORWARD_SCAN_BLOCKS}-block limit on the forward scan used for \
+                 self-destructed/EOA accounts; pass a --from-block closer to the suspected \
+                 deployment.",
+                high.saturating_sub(from_block)
+            ));
+        }
+
+        scan_forward_for_creation_block(contract, provider, from_block, high).await?
+    };
+
+    let (init_code, method, creation_tx_hash, deployer) = find_creation_trace_in_block(
+        contract,
+        provider,
+        creation_block,
+        select_init_code_hash,
+    )
+    .await?;
+
+    let init_code_hash = keccak256(&init_code);
+    let provenance = CreationProvenance { method, deployer, creation_tx_hash, init_code_hash };
+
+    Ok((init_code, provenance))
+}
+
+/// Binary-searches `[low, high]` for the first block at which `contract` has non-empty code.
+/// Assumes code presence is monotonic over the range, i.e. the account is not self-destructed.
+async fn binary_search_creation_block(
+    contract: Address,
+    provider: &RetryProvider,
+    mut low: u64,
+    mut high: u64,
+) -> Result<u64> {
+    let range_start = low;
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let code = provider.get_code_at(contract).block_id(mid.into()).await?;
+        if code.is_empty() {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    // The search above assumes code presence never flips back to empty once it's first seen -
+    // true for a single deployment, but not for an account that was self-destructed and later
+    // redeployed (e.g. via CREATE2) at the same address while still holding code at `latest`.
+    // In that case the search can converge on an arbitrary later deployment instead of the
+    // first one, with nothing downstream able to tell. Guard against silently trusting a wrong
+    // result by checking that the block right before `low` really had no code, which is the one
+    // invariant a true first-deployment boundary must satisfy.
+    if low > 0 {
+        let previous_code = provider.get_code_at(contract).block_id((low - 1).into()).await?;
+        if !previous_code.is_empty() {
+            if low == range_start {
+                return Err(eyre::eyre!(
+                    "{contract} already had code at block {low}, the start of the searched \
+                     range; --from-block is later than {contract}'s actual deployment. Pass an \
+                     earlier --from-block (or omit it to search from block 0)."
+                ));
+            }
+            return Err(eyre::eyre!(
+                "{contract}'s code presence isn't monotonic in blocks [{range_start}, {high}] \
+                 (it was likely self-destructed and redeployed); a binary search can't reliably \
+                 locate its true creation block here. Pass a --from-block right before the \
+                 specific deployment you're interested in."
+            ));
+        }
+    }
+
+    Ok(low)
+}
+
+/// Upper bound on the size of the range [`scan_forward_for_creation_block`] will walk block by
+/// block. Without a bound, a self-destructed or never-deployed contract with no `--from-block`
+/// hint would make the fallback issue millions of sequential `eth_getCode` calls before erroring.
+const MAX_FORWARD_SCAN_BLOCKS: u64 = 100_000;
+
+/// Scans `[low, high]` block by block for the first block at which `contract` has non-empty
+/// code. Used as a fallback when code presence is not monotonic (e.g. self-destructed accounts).
+/// Callers must keep `high - low` within [`MAX_FORWARD_SCAN_BLOCKS`].
+async fn scan_forward_for_creation_block(
+    contract: Address,
+    provider: &RetryProvider,
+    low: u64,
+    high: u64,
+) -> Result<u64> {
+    for block in low..=high {
+        if !provider.get_code_at(contract).block_id(block.into()).await?.is_empty() {
+            return Ok(block);
+        }
+    }
+
+    Err(eyre::eyre!(
+        "{contract} has no code in any block in [{low}, {high}]; it may be an EOA, or it was \
+         created and self-destructed outside the scanned range. Try a lower --from-block."
+    ))
+}
+
+/// Scans every trace in `block` for a `CREATE`/`CREATE2` action whose resulting address matches
+/// `contract`, returning its init code, inferred creation method, transaction hash and deployer.
+///
+/// The creation method is inferred from the trace's nesting: a trace with an empty
+/// `trace_address` is the top-level call of its transaction, which the EVM only allows to be a
+/// plain `CREATE`; anything nested is reported as `CREATE2`.
+///
+/// More than one candidate means `contract` was redeployed (e.g. via CREATE2) at the same
+/// address within the same block. If `select_init_code_hash` is set, the candidate whose init
+/// code hashes to it is returned; otherwise every candidate's transaction hash and init-code hash
+/// are listed so the user can re-run with `--init-code-hash` to pick one.
+async fn find_creation_trace_in_block(
+    contract: Address,
+    provider: &RetryProvider,
+    block: u64,
+    select_init_code_hash: Option<B256>,
+) -> Result<(Bytes, CreationMethod, B256, Address)> {
+    let traces = provider
+        .trace_block(block.into())
+        .await
+        .map_err(|e| eyre::eyre!("Could not fetch traces for block {block}: {e}"))?;
+
+    let mut candidates = Vec::new();
+    for trace in traces {
+        if let Some(TraceOutput::Create(CreateOutput { address, code: _, gas_used: _ })) =
+            trace.trace.result
+        {
+            if address == contract {
+                if let Action::Create(CreateAction { init, value: _, from, gas: _ }) =
+                    trace.trace.action
+                {
+                    let method = if trace.trace.trace_address.is_empty() {
+                        CreationMethod::Create
+                    } else {
+                        CreationMethod::Create2
+                    };
+                    let Some(creation_tx_hash) = trace.transaction_hash else {
+                        continue;
+                    };
+                    candidates.push((init, method, creation_tx_hash, from));
+                }
+            }
+        }
+    }
+
+    select_creation_candidate(candidates, contract, block, select_init_code_hash)
+}
+
+/// Picks the right creation trace out of every `Create`/`Create2` candidate found for `contract`
+/// in `block`. Pulled out of [`find_creation_trace_in_block`] so the disambiguation logic can be
+/// tested without a live `trace_block` call.
+fn select_creation_candidate(
+    mut candidates: Vec<(Bytes, CreationMethod, B256, Address)>,
+    contract: Address,
+    block: u64,
+    select_init_code_hash: Option<B256>,
+) -> Result<(Bytes, CreationMethod, B256, Address)> {
+    if let Some(wanted) = select_init_code_hash {
+        return candidates
+            .into_iter()
+            .find(|(init, _, _, _)| keccak256(init) == wanted)
+            .ok_or_else(|| {
+                eyre::eyre!(
+                    "No creation trace for {contract} in block {block} has init-code hash \
+                     {wanted}."
+                )
+            });
+    }
+
+    match candidates.len() {
+        0 => Err(eyre::eyre!("Could not find a creation trace for {contract} in block {block}.")),
+        1 => Ok(candidates.remove(0)),
+        _ => {
+            let listing = candidates
+                .iter()
+                .map(|(init, _, tx_hash, _)| {
+                    format!("  tx {tx_hash}, init-code hash {}", keccak256(init))
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            Err(eyre::eyre!(
+                "Found {} candidate creation traces for {contract} in block {block} (likely a \
+                 CREATE2 redeployment at the same address):\n{listing}\nRe-run with \
+                 --init-code-hash <HASH> (alongside --rpc-only) to pick one.",
+                candidates.len()
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod select_creation_candidate_tests {
+    use super::*;
+
+    fn candidate(init: &[u8]) -> (Bytes, CreationMethod, B256, Address) {
+        (
+            Bytes::from(init.to_vec()),
+            CreationMethod::Create2,
+            B256::repeat_byte(0xaa),
+            Address::repeat_byte(0xbb),
+        )
+    }
+
+    #[test]
+    fn select_creation_candidate_errors_on_zero_candidates() {
+        let result = select_creation_candidate(vec![], Address::repeat_byte(0x01), 100, None);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn select_creation_candidate_picks_sole_candidate() {
+        let only = candidate(&[0x60, 0x01]);
+        let result =
+            select_creation_candidate(vec![only.clone()], Address::repeat_byte(0x01), 100, None)
+                .unwrap();
+        assert_eq!(result, only);
+    }
+
+    #[test]
+    fn select_creation_candidate_errors_on_multiple_without_selection() {
+        let first = candidate(&[0x60, 0x01]);
+        let second = candidate(&[0x60, 0x02]);
+        let result =
+            select_creation_candidate(vec![first, second], Address::repeat_byte(0x01), 100, None);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn select_creation_candidate_picks_matching_init_code_hash() {
+        let first = candidate(&[0x60, 0x01]);
+        let second = candidate(&[0x60, 0x02]);
+        let wanted = keccak256(&second.0);
+
+        let result = select_creation_candidate(
+            vec![first, second.clone()],
+            Address::repeat_byte(0x01),
+            100,
+            Some(wanted),
+        )
+        .unwrap();
+        assert_eq!(result, second);
+    }
+
+    #[test]
+    fn select_creation_candidate_errors_when_no_candidate_matches_hash() {
+        let first = candidate(&[0x60, 0x01]);
+        let second = candidate(&[0x60, 0x02]);
+        let result = select_creation_candidate(
+            vec![first, second],
+            Address::repeat_byte(0x01),
+            100,
+            Some(B256::repeat_byte(0xff)),
+        );
+        assert!(result.is_err());
+    }
 }